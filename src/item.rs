@@ -1,5 +1,5 @@
 use std::{
-	mem::MaybeUninit,
+	mem::{MaybeUninit, ManuallyDrop},
 	cmp::{
 		PartialOrd,
 		Ord,
@@ -42,7 +42,10 @@ impl fmt::Debug for ItemAddr {
 }
 
 pub struct Item<K, V> {
-	key: MaybeUninit<K>,
+	/// Always initialized: `ManuallyDrop` only opts the field out of the
+	/// automatic field-by-field drop, it makes no claim of possible
+	/// uninitialization the way `MaybeUninit` does.
+	key: ManuallyDrop<K>,
 
 	/// # Safety
 	///
@@ -53,14 +56,14 @@ pub struct Item<K, V> {
 impl<K, V> Item<K, V> {
 	pub fn new(key: K, value: V) -> Item<K, V> {
 		Item {
-			key: MaybeUninit::new(key),
+			key: ManuallyDrop::new(key),
 			value: MaybeUninit::new(value)
 		}
 	}
 
 	#[inline]
 	pub fn key(&self) -> &K {
-		unsafe { self.key.assume_init_ref() }
+		&self.key
 	}
 
 	#[inline]
@@ -87,27 +90,22 @@ impl<K, V> Item<K, V> {
 
 	#[inline]
 	pub fn into_value(self) -> V {
-		let (key, value) = self.into_inner();
-		unsafe {
-			std::mem::drop(key.assume_init());
-			value.assume_init()
-		}
+		let (_key, value) = self.into_inner();
+		unsafe { value.assume_init() }
 	}
 
 	/// Drop the key but not the value which is assumed uninitialized.
 	#[inline]
 	pub unsafe fn forget_value(self) {
-		let (key, value) = self.into_inner();
-		std::mem::drop(key.assume_init());
+		let (_key, value) = self.into_inner();
 		std::mem::forget(value);
 	}
 
 	#[inline]
-	pub fn into_inner(mut self) -> (MaybeUninit<K>, MaybeUninit<V>) {
-		let mut key = MaybeUninit::uninit();
+	pub fn into_inner(mut self) -> (K, MaybeUninit<V>) {
 		let mut value = MaybeUninit::uninit();
-		std::mem::swap(&mut key, &mut self.key);
 		std::mem::swap(&mut value, &mut self.value);
+		let key = unsafe { ManuallyDrop::take(&mut self.key) };
 		std::mem::forget(self);
 		(key, value)
 	}
@@ -115,10 +113,20 @@ impl<K, V> Item<K, V> {
 
 impl<K, V> Drop for Item<K, V> {
 	fn drop(&mut self) {
-		unsafe {
-			std::ptr::drop_in_place(self.key.assume_init_mut());
-			std::ptr::drop_in_place(self.value.assume_init_mut());
+		// Guard that drops `value` when it goes out of scope, whether that's
+		// because `key`'s destructor returned normally or because it unwound.
+		// Without this, a panicking key destructor would skip the value's
+		// drop entirely and leak it.
+		struct ValueGuard<'a, V>(&'a mut MaybeUninit<V>);
+
+		impl<'a, V> Drop for ValueGuard<'a, V> {
+			fn drop(&mut self) {
+				unsafe { std::ptr::drop_in_place(self.0.assume_init_mut()) }
+			}
 		}
+
+		let _guard = ValueGuard(&mut self.value);
+		unsafe { ManuallyDrop::drop(&mut self.key) }
 	}
 }
 
@@ -145,3 +153,36 @@ impl<K: Ord + PartialEq, V> PartialOrd for Item<K, V> {
 		Some(self.key().cmp(other.key()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+	struct PanicOnDrop;
+
+	impl Drop for PanicOnDrop {
+		fn drop(&mut self) {
+			panic!("key drop panicked");
+		}
+	}
+
+	struct CountOnDrop<'a>(&'a AtomicUsize);
+
+	impl<'a> Drop for CountOnDrop<'a> {
+		fn drop(&mut self) {
+			self.0.fetch_add(1, AtomicOrdering::SeqCst);
+		}
+	}
+
+	#[test]
+	fn value_is_dropped_when_key_drop_panics() {
+		let value_dropped = AtomicUsize::new(0);
+		let item = Item::new(PanicOnDrop, CountOnDrop(&value_dropped));
+
+		let unwound = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(item)));
+
+		assert!(unwound.is_err());
+		assert_eq!(value_dropped.load(AtomicOrdering::SeqCst), 1);
+	}
+}